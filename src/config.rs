@@ -0,0 +1,223 @@
+//! Configuration for where parameters live and how we authenticate to reach them.
+//!
+//! This mirrors the provider chain object_store's AWS support exposes: static
+//! access-key/secret pairs, the default profile/environment chain, EC2 instance
+//! metadata, and the web identity token flow used for EKS IRSA.
+
+use crate::ssm_client::SsmClient;
+use rusoto_core::Region;
+use std::error::Error;
+use std::fs::File;
+use std::io::prelude::Read as IoRead;
+use yaml_rust::YamlLoader;
+
+/// Where to pull AWS credentials from.
+#[derive(Debug, Clone)]
+pub enum Credentials {
+    /// A fixed access key/secret key pair (optionally with a session token).
+    Static {
+        /// AWS access key ID
+        access_key_id: String,
+        /// AWS secret access key
+        secret_access_key: String,
+        /// Optional STS session token
+        session_token: Option<String>,
+    },
+    /// The default chain: environment variables, then the shared `~/.aws` profile.
+    Environment,
+    /// Credentials vended by the EC2 instance metadata service.
+    InstanceMetadata,
+    /// The web identity token flow used for EKS IAM Roles for Service Accounts (IRSA).
+    WebIdentity {
+        /// Path to the projected service account token file
+        token_file: String,
+        /// ARN of the role to assume
+        role_arn: String,
+        /// Optional session name; a generated one is used when omitted
+        session_name: Option<String>,
+    },
+}
+
+impl Default for Credentials {
+    fn default() -> Self {
+        Credentials::Environment
+    }
+}
+
+/// Top level configuration: which region to talk to and how to authenticate.
+///
+/// # File Structure
+/// ```yaml
+/// region: "us-west-2"
+/// credentials:
+///   type: "static"
+///   access_key_id: "AKIA..."
+///   secret_access_key: "..."
+/// ```
+#[derive(Debug, Clone)]
+pub struct AppConfig {
+    /// AWS region that parameters are read from and written to
+    pub region: Region,
+    /// Credential source used to authenticate SSM requests
+    pub credentials: Credentials,
+}
+
+impl AppConfig {
+    /// Loads configuration from a YAML file when `path` is given, falling back to
+    /// environment variables (`AWS_REGION`, `AWS_ACCESS_KEY_ID`/`AWS_SECRET_ACCESS_KEY`)
+    /// and the default credential chain otherwise.
+    pub fn load(path: Option<&str>) -> Result<AppConfig, Box<dyn Error>> {
+        match path {
+            Some(path) => AppConfig::from_file(path),
+            None => Ok(AppConfig::from_env()),
+        }
+    }
+
+    fn from_file(path: &str) -> Result<AppConfig, Box<dyn Error>> {
+        let mut file = File::open(path)?;
+        let mut contents = String::new();
+        file.read_to_string(&mut contents)?;
+
+        let docs = YamlLoader::load_from_str(&contents)?;
+        let doc = docs.first().unwrap_or(&yaml_rust::Yaml::BadValue);
+
+        let region = doc["region"]
+            .as_str()
+            .map(|region| region.parse())
+            .transpose()?
+            .unwrap_or(Region::UsWest2);
+
+        let credentials = match doc["credentials"]["type"].as_str() {
+            Some("static") => Credentials::Static {
+                access_key_id: doc["credentials"]["access_key_id"]
+                    .as_str()
+                    .ok_or("credentials.access_key_id missing")?
+                    .to_string(),
+                secret_access_key: doc["credentials"]["secret_access_key"]
+                    .as_str()
+                    .ok_or("credentials.secret_access_key missing")?
+                    .to_string(),
+                session_token: doc["credentials"]["session_token"]
+                    .as_str()
+                    .map(String::from),
+            },
+            Some("instance_metadata") => Credentials::InstanceMetadata,
+            Some("web_identity") => Credentials::WebIdentity {
+                token_file: doc["credentials"]["token_file"]
+                    .as_str()
+                    .ok_or("credentials.token_file missing")?
+                    .to_string(),
+                role_arn: doc["credentials"]["role_arn"]
+                    .as_str()
+                    .ok_or("credentials.role_arn missing")?
+                    .to_string(),
+                session_name: doc["credentials"]["session_name"]
+                    .as_str()
+                    .map(String::from),
+            },
+            _ => Credentials::Environment,
+        };
+
+        Ok(AppConfig { region, credentials })
+    }
+
+    fn from_env() -> AppConfig {
+        let region = std::env::var("AWS_REGION")
+            .ok()
+            .and_then(|region| region.parse().ok())
+            .unwrap_or(Region::UsWest2);
+
+        let credentials = match (
+            std::env::var("AWS_ACCESS_KEY_ID"),
+            std::env::var("AWS_SECRET_ACCESS_KEY"),
+        ) {
+            (Ok(access_key_id), Ok(secret_access_key)) => Credentials::Static {
+                access_key_id,
+                secret_access_key,
+                session_token: std::env::var("AWS_SESSION_TOKEN").ok(),
+            },
+            _ => Credentials::Environment,
+        };
+
+        AppConfig { region, credentials }
+    }
+}
+
+/// Builds an `SsmClient` wired up to the requested region and credential source.
+#[cfg(not(feature = "native-sigv4"))]
+pub fn build_client(region: Region, credentials: Credentials) -> Result<SsmClient, Box<dyn Error>> {
+    use rusoto_core::request::HttpClient;
+    use rusoto_credential::{ChainProvider, InstanceMetadataProvider, StaticProvider};
+    use rusoto_sts::WebIdentityProvider;
+
+    let dispatcher = HttpClient::new()?;
+
+    let client = match credentials {
+        Credentials::Static {
+            access_key_id,
+            secret_access_key,
+            session_token,
+        } => {
+            let provider = StaticProvider::new(access_key_id, secret_access_key, session_token, None);
+            rusoto_ssm::SsmClient::new_with(dispatcher, provider, region)
+        }
+        Credentials::Environment => {
+            // ChainProvider tries EnvironmentProvider before falling back to the
+            // shared ~/.aws profile, matching this variant's documented precedence.
+            let provider = ChainProvider::new();
+            rusoto_ssm::SsmClient::new_with(dispatcher, provider, region)
+        }
+        Credentials::InstanceMetadata => {
+            rusoto_ssm::SsmClient::new_with(dispatcher, InstanceMetadataProvider::new(), region)
+        }
+        Credentials::WebIdentity {
+            token_file,
+            role_arn,
+            session_name,
+        } => {
+            // WebIdentityProvider reads its inputs from the same env vars the
+            // AWS SDKs use for IRSA, so set them from our config before building it.
+            std::env::set_var("AWS_WEB_IDENTITY_TOKEN_FILE", &token_file);
+            std::env::set_var("AWS_ROLE_ARN", &role_arn);
+            if let Some(session_name) = &session_name {
+                std::env::set_var("AWS_ROLE_SESSION_NAME", session_name);
+            }
+            rusoto_ssm::SsmClient::new_with(dispatcher, WebIdentityProvider::from_k8s_env(), region)
+        }
+    };
+
+    Ok(SsmClient::new(client))
+}
+
+/// Builds an `SsmClient` backed by the dependency-free native SigV4 backend.
+/// Only static keys or the `AWS_ACCESS_KEY_ID`/`AWS_SECRET_ACCESS_KEY` env vars
+/// are supported here; instance metadata and web identity credentials still
+/// require the rusoto backend.
+#[cfg(feature = "native-sigv4")]
+pub fn build_client(region: Region, credentials: Credentials) -> Result<SsmClient, Box<dyn Error>> {
+    use crate::native_ssm::NativeSsmClient;
+
+    let region_name = region.name().to_string();
+
+    match credentials {
+        Credentials::Static {
+            access_key_id,
+            secret_access_key,
+            session_token,
+        } => Ok(NativeSsmClient::new(
+            region_name,
+            access_key_id,
+            secret_access_key,
+            session_token,
+        )),
+        Credentials::Environment => Ok(NativeSsmClient::new(
+            region_name,
+            std::env::var("AWS_ACCESS_KEY_ID")?,
+            std::env::var("AWS_SECRET_ACCESS_KEY")?,
+            std::env::var("AWS_SESSION_TOKEN").ok(),
+        )),
+        Credentials::InstanceMetadata | Credentials::WebIdentity { .. } => {
+            Err("the native-sigv4 backend only supports static or environment credentials".into())
+        }
+    }
+}
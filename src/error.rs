@@ -0,0 +1,52 @@
+//! Typed errors for parameter read/write/import failures, so callers can tell a
+//! missing credential from a missing parameter from a malformed YAML entry
+//! instead of getting back an opaque `NoneError`.
+
+use thiserror::Error;
+
+/// Something went wrong reading, writing, or resolving a parameter.
+#[derive(Debug, Error)]
+pub enum ParameterError {
+    /// No such parameter exists in SSM
+    #[error("parameter {name} not found")]
+    NotFound {
+        /// Name of the parameter that was requested
+        name: String,
+    },
+
+    /// The configured credentials could not be loaded, or SSM rejected them
+    #[error("credentials error: {message}")]
+    Credentials {
+        /// Message from the credentials provider, or from SSM when it rejects the credentials
+        message: String,
+    },
+
+    /// A YAML parameter entry was missing or had the wrong type for `field`
+    #[error("parameter entry {index} is missing or has a malformed `{field}` field")]
+    MalformedYaml {
+        /// Name of the field that was missing or malformed
+        field: String,
+        /// Index of the offending entry, counted across every document in the YAML file
+        index: usize,
+    },
+
+    /// The YAML document itself failed to parse
+    #[error("could not parse YAML: {message}")]
+    InvalidYaml {
+        /// Message returned by the YAML parser
+        message: String,
+    },
+
+    /// SSM rejected the `PutParameter` request
+    #[error("failed to put parameter {name}: {message}")]
+    PutFailed {
+        /// Name of the parameter that failed to update
+        name: String,
+        /// Message returned by SSM
+        message: String,
+    },
+
+    /// An I/O error occurred while reading or writing a file
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+}
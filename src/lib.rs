@@ -1,20 +1,30 @@
 //! # AWS Parameter Update Library
 //!
 //! `aws_parameter_update` is a small tool used to quickly update simple AWS Parameters
-#![feature(try_trait)]
-
 #![warn(missing_docs)]
 #![warn(missing_doc_code_examples)]
 
+pub mod config;
+mod error;
+#[cfg(feature = "native-sigv4")]
+mod native_ssm;
 mod parameter;
-
-use log::{error, info};
+mod resolve;
+#[cfg(feature = "native-sigv4")]
+mod sigv4;
+mod ssm_client;
+mod updater;
+
+use log::info;
+pub use config::{AppConfig, Credentials};
+pub use error::ParameterError;
 pub use parameter::Parameter;
-use rusoto_core::Region;
-use rusoto_ssm::SsmClient;
+pub use resolve::resolve_file;
+pub use ssm_client::SsmClient;
+pub use updater::ParameterUpdater;
+use std::error::Error;
 use std::fs::File;
 use std::io::prelude::Read;
-use std::{error::Error, iter::Map};
 use yaml_rust::YamlLoader;
 
 /// Updates AWS Parameters from a YAML file
@@ -34,7 +44,7 @@ use yaml_rust::YamlLoader;
 ///
 /// # Example
 ///
-/// ```should_panic because there is no file
+/// ```
 /// let filename = "non_existing_file.yaml";
 ///
 /// match tokio_test::block_on(aws_parameter_update::update_from_file(filename)) {
@@ -47,9 +57,7 @@ use yaml_rust::YamlLoader;
 /// };
 /// ```
 pub async fn update_from_file(filename: &str) -> Result<(), Box<dyn (Error)>> {
-    let parameters_from_yaml = read_parameters_yaml(&filename)?;
-
-    update_parameters(parameters_from_yaml).await
+    ParameterUpdater::default().update_from_file(filename).await
 }
 
 /// Updates AWS Parameter from calling function input
@@ -77,7 +85,9 @@ pub async fn update_parameter(
     description: &str,
     is_secure: bool,
 ) -> Result<(), Box<dyn (Error)>> {
-    update_parameters(vec![Parameter::new(name, value, description, is_secure)]).await
+    ParameterUpdater::default()
+        .update_parameter(name, value, description, is_secure)
+        .await
 }
 
 /// Updates AWS Parameters from calling function input
@@ -110,42 +120,43 @@ pub async fn update_parameter(
 /// };
 /// ```
 pub async fn update_parameters(parameters: Vec<Parameter>) -> Result<(), Box<dyn (Error)>> {
-    let client = SsmClient::new(Region::UsWest2);
-
-    for parameter in parameters {
-        match parameter.update(&client).await {
-            Ok(parameter_name) => info!("Parameter {} processed", parameter_name),
-            Err(_error) => error!("Parameter not updated"),
-        }
-    }
-
-    info!("Parameter update finished running");
+    ParameterUpdater::default().update_parameters(parameters).await?;
     Ok(())
 }
 
-fn read_parameters_yaml(filename: &str) -> Result<Vec<Parameter>, Box<dyn (Error)>> {
-    let mut file = File::open(filename).expect("Unable to open parameter input file");
+fn read_parameters_yaml(filename: &str) -> Result<Vec<Parameter>, ParameterError> {
+    let mut file = File::open(filename)?;
     let mut contents = String::new();
 
-    file.read_to_string(&mut contents)
-        .expect("Unable to read parameter input file");
+    file.read_to_string(&mut contents)?;
 
     // YamlLoader returns a "doc" which can have multiple YAML files in it,
-    // hence the two iterators and the flattening
-    let parameters = YamlLoader::load_from_str(&contents)?
+    // hence the flat_map over documents before indexing entries.
+    let parameters = YamlLoader::load_from_str(&contents)
+        .map_err(|error| ParameterError::InvalidYaml {
+            message: error.to_string(),
+        })?
         .into_iter()
-        .map(|yaml_document| -> Map<_, _> {
-            yaml_document.into_iter().map(|param| -> Parameter {
-                Parameter::new(
-                    param["name"].as_str().expect("name missing"),
-                    param["value"].as_str().expect("value missing"),
-                    param["description"].as_str().expect("description missing"),
-                    param["is_secure"].as_bool().expect("is_secure missing"),
-                )
-            })
+        .flat_map(|yaml_document| yaml_document.into_iter().collect::<Vec<_>>())
+        .enumerate()
+        .map(|(index, param)| -> Result<Parameter, ParameterError> {
+            let field = |field: &str| ParameterError::MalformedYaml {
+                field: field.to_string(),
+                index,
+            };
+
+            Ok(Parameter::new(
+                param["name"].as_str().ok_or_else(|| field("name"))?,
+                param["value"].as_str().ok_or_else(|| field("value"))?,
+                param["description"]
+                    .as_str()
+                    .ok_or_else(|| field("description"))?,
+                param["is_secure"]
+                    .as_bool()
+                    .ok_or_else(|| field("is_secure"))?,
+            ))
         })
-        .flatten()
-        .collect::<Vec<_>>();
+        .collect::<Result<Vec<_>, _>>()?;
 
     info!("Parameters YAML loaded");
     Ok(parameters)
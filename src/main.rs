@@ -1,13 +1,13 @@
-#![feature(try_trait)]
-
 #[cfg(test)]
 mod tests;
 
-use clap::{crate_version, App, Arg};
+use aws_parameter_update::{AppConfig, ParameterUpdater};
+use clap::{crate_version, App, Arg, ArgMatches, SubCommand};
 use log::{error, info, warn};
 use std::error::Error;
 
-fn main() -> Result<(), Box<dyn Error>> {
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
     simple_logger::init_with_level(log::Level::Info)?;
 
     info!("Starting aws_parameter_update...");
@@ -15,92 +15,211 @@ fn main() -> Result<(), Box<dyn Error>> {
     let matches = App::new("AWS Parameter Updater")
         .version(crate_version!())
         .author("Lane Sawyer <github@lanesawyer.dev>")
-        .about("Allows you to update AWS Parameters using a YAML file or directly from the CLI")
+        .about("Allows you to read and write AWS Parameters using a YAML file or directly from the CLI")
         .arg(
-            Arg::with_name("filename")
-                .help("Sets the input file to use")
-                .short("f")
-                .long("filename")
-                .value_name("filename")
+            Arg::with_name("config")
+                .help("Sets a YAML config file specifying the AWS region and credential source")
+                .short("c")
+                .long("config")
+                .value_name("config_file")
+                .global(true)
                 .takes_value(true),
         )
-        .arg(
-            Arg::with_name("name")
-                .help("New parameter name")
-                .short("n")
-                .long("name")
-                .value_name("name")
-                .conflicts_with("filename")
-                .requires_all(&["value", "description"])
-                .takes_value(true),
+        .subcommand(
+            SubCommand::with_name("put")
+                .about("Updates a single existing parameter; a parameter not already in SSM is not created")
+                .arg(
+                    Arg::with_name("name")
+                        .help("Parameter name")
+                        .short("n")
+                        .long("name")
+                        .value_name("name")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("value")
+                        .help("Parameter value")
+                        .short("v")
+                        .long("value")
+                        .value_name("value")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("description")
+                        .help("Parameter description")
+                        .short("d")
+                        .long("description")
+                        .value_name("description")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("secure")
+                        .help("Stores the parameter securely")
+                        .short("s")
+                        .long("secure"),
+                ),
         )
-        .arg(
-            Arg::with_name("value")
-                .help("New parameter value")
-                .short("v")
-                .long("value")
-                .value_name("value")
-                .requires_all(&["name", "description"])
-                .conflicts_with("filename")
-                .takes_value(true),
+        .subcommand(
+            SubCommand::with_name("import")
+                .about("Updates every existing parameter listed in a YAML file; parameters not already in SSM are not created")
+                .arg(
+                    Arg::with_name("filename")
+                        .help("Sets the input file to use")
+                        .short("f")
+                        .long("filename")
+                        .value_name("filename")
+                        .required(true)
+                        .takes_value(true),
+                ),
         )
-        .arg(
-            Arg::with_name("description")
-                .help("New parameter decription")
-                .short("d")
-                .long("description")
-                .value_name("description")
-                .requires_all(&["name", "value"])
-                .conflicts_with("filename")
-                .takes_value(true),
+        .subcommand(
+            SubCommand::with_name("get")
+                .about("Prints a single parameter's value")
+                .arg(
+                    Arg::with_name("name")
+                        .help("Parameter name")
+                        .required(true)
+                        .index(1),
+                ),
         )
-        .arg(
-            Arg::with_name("secure")
-                .help("Stores the parameter securely")
-                .short("s")
-                .long("secure")
-                .requires_all(&["name", "value", "description"])
-                .conflicts_with("filename"),
+        .subcommand(
+            SubCommand::with_name("list")
+                .about("Lists parameters under a path")
+                .arg(
+                    Arg::with_name("path")
+                        .help("Parameter path prefix")
+                        .long("path")
+                        .value_name("path")
+                        .default_value("/"),
+                )
+                .arg(
+                    Arg::with_name("recursive")
+                        .help("Walks nested paths under the prefix")
+                        .long("recursive"),
+                ),
         )
-        .arg(
-            Arg::with_name("config")
-                .help("Sets a custom config file, which currently does nothing")
-                .short("c")
-                .long("config")
-                .value_name("config_file")
-                .takes_value(true),
+        .subcommand(
+            SubCommand::with_name("delete")
+                .about("Deletes a single parameter")
+                .arg(
+                    Arg::with_name("name")
+                        .help("Parameter name")
+                        .required(true)
+                        .index(1),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("export")
+                .about("Dumps parameters under a path back into the import YAML schema")
+                .arg(
+                    Arg::with_name("path")
+                        .help("Parameter path prefix")
+                        .long("path")
+                        .value_name("path")
+                        .default_value("/"),
+                )
+                .arg(
+                    Arg::with_name("recursive")
+                        .help("Walks nested paths under the prefix")
+                        .long("recursive"),
+                )
+                .arg(
+                    Arg::with_name("filename")
+                        .help("Writes the YAML to a file instead of stdout")
+                        .short("f")
+                        .long("filename")
+                        .value_name("filename")
+                        .takes_value(true),
+                ),
         )
         .get_matches();
 
-    if matches.is_present("filename") {
-        let filename = matches.value_of("filename").unwrap();
-        match aws_parameter_update::update_from_file(filename) {
-            Ok(_) => {
-                info!("Parameter update finished");
-            }
-            Err(error) => {
-                error!("Parameter updated failed: {}", error);
-            }
-        };
-    } else if matches.is_present("name") {
-        let name = matches.value_of("name").unwrap();
-        let value = matches.value_of("value").unwrap();
-        let description = matches.value_of("description").unwrap();
-        let is_secure = matches.is_present("secure");
-
-        match aws_parameter_update::update_parameter(name, value, description, is_secure) {
-            Ok(_) => {
-                info!("Parameter update finished");
-            }
-            Err(error) => {
-                error!("Parameter updated failed: {}", error);
-            }
-        };
-    } else {
-        warn!("No input was provided. Use -h or --help to see valid input options")
+    let app_config = AppConfig::load(matches.value_of("config"))?;
+    let updater = ParameterUpdater::from_config(&app_config)?;
+
+    match matches.subcommand() {
+        ("put", Some(sub_matches)) => run_put(&updater, sub_matches).await,
+        ("import", Some(sub_matches)) => run_import(&updater, sub_matches).await,
+        ("get", Some(sub_matches)) => run_get(&updater, sub_matches).await,
+        ("list", Some(sub_matches)) => run_list(&updater, sub_matches).await,
+        ("delete", Some(sub_matches)) => run_delete(&updater, sub_matches).await,
+        ("export", Some(sub_matches)) => run_export(&updater, sub_matches).await,
+        _ => warn!("No subcommand was provided. Use -h or --help to see valid commands"),
     }
 
     info!("Ending AWS parameter updates");
 
     Ok(())
 }
+
+async fn run_put(updater: &ParameterUpdater, matches: &ArgMatches<'_>) {
+    let name = matches.value_of("name").unwrap();
+    let value = matches.value_of("value").unwrap();
+    let description = matches.value_of("description").unwrap();
+    let is_secure = matches.is_present("secure");
+
+    match updater.update_parameter(name, value, description, is_secure).await {
+        Ok(_) => info!("Parameter update finished"),
+        Err(error) => error!("Parameter update failed: {}", error),
+    };
+}
+
+async fn run_import(updater: &ParameterUpdater, matches: &ArgMatches<'_>) {
+    let filename = matches.value_of("filename").unwrap();
+
+    match updater.update_from_file(filename).await {
+        Ok(_) => info!("Parameter import finished"),
+        Err(error) => error!("Parameter import failed: {}", error),
+    };
+}
+
+async fn run_get(updater: &ParameterUpdater, matches: &ArgMatches<'_>) {
+    let name = matches.value_of("name").unwrap();
+
+    match updater.get_parameter(name).await {
+        Ok(value) => println!("{}", value),
+        Err(error) => error!("Could not retrieve parameter {}: {}", name, error),
+    };
+}
+
+async fn run_list(updater: &ParameterUpdater, matches: &ArgMatches<'_>) {
+    let path = matches.value_of("path").unwrap();
+    let recursive = matches.is_present("recursive");
+
+    match updater.list_parameters(path, recursive).await {
+        Ok(parameters) => {
+            for parameter in parameters {
+                println!("{}\t{}", parameter.name, parameter.value);
+            }
+        }
+        Err(error) => error!("Could not list parameters under {}: {}", path, error),
+    };
+}
+
+async fn run_delete(updater: &ParameterUpdater, matches: &ArgMatches<'_>) {
+    let name = matches.value_of("name").unwrap();
+
+    match updater.delete_parameter(name).await {
+        Ok(_) => info!("Parameter {} deleted", name),
+        Err(error) => error!("Could not delete parameter {}: {}", name, error),
+    };
+}
+
+async fn run_export(updater: &ParameterUpdater, matches: &ArgMatches<'_>) {
+    let path = matches.value_of("path").unwrap();
+    let recursive = matches.is_present("recursive");
+
+    match updater.export_parameters_yaml(path, recursive).await {
+        Ok(yaml) => match matches.value_of("filename") {
+            Some(filename) => match std::fs::write(filename, yaml) {
+                Ok(_) => info!("Exported parameters under {} to {}", path, filename),
+                Err(error) => error!("Could not write export file {}: {}", filename, error),
+            },
+            None => println!("{}", yaml),
+        },
+        Err(error) => error!("Could not export parameters under {}: {}", path, error),
+    };
+}
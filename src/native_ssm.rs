@@ -0,0 +1,193 @@
+//! Dependency-free SSM client that talks directly to the HTTP API over
+//! `reqwest`, signing each request by hand with SigV4 instead of going through
+//! rusoto. Enabled with the `native-sigv4` feature.
+
+use crate::sigv4::sign_request;
+use crate::ParameterError;
+use chrono::Utc;
+use reqwest::Client;
+use rusoto_ssm::{
+    DeleteParameterRequest, DeleteParameterResult, GetParameterRequest, GetParameterResult,
+    GetParametersByPathRequest, GetParametersByPathResult, PutParameterRequest,
+    PutParameterResult,
+};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Talks to SSM over `reqwest` with a hand-rolled SigV4 `Authorization`
+/// header, rather than going through the rusoto stack. Request/response
+/// bodies reuse rusoto_ssm's generated structs, since those already mirror
+/// the JSON shapes `AmazonSSM.*` expects.
+pub struct NativeSsmClient {
+    http: Client,
+    region: String,
+    access_key_id: String,
+    secret_access_key: String,
+    session_token: Option<String>,
+}
+
+/// Shape of the error body `AmazonSSM.*` returns on a non-2xx response.
+#[derive(Debug, serde::Deserialize, Default)]
+struct AwsErrorBody {
+    #[serde(rename = "__type", default)]
+    error_type: String,
+    #[serde(default)]
+    message: String,
+}
+
+/// `__type` substrings that indicate a problem with the caller's credentials
+/// (unrecognized, expired, badly signed, or lacking permission), as opposed to
+/// the request being rejected for some other reason.
+const CREDENTIALS_ERROR_TYPES: &[&str] = &[
+    "AccessDenied",
+    "UnrecognizedClientException",
+    "InvalidClientTokenId",
+    "InvalidSignatureException",
+    "SignatureDoesNotMatch",
+    "MissingAuthenticationToken",
+    "ExpiredToken",
+];
+
+impl NativeSsmClient {
+    /// Builds a client for `region` using a fixed access key/secret pair.
+    pub fn new(
+        region: impl Into<String>,
+        access_key_id: impl Into<String>,
+        secret_access_key: impl Into<String>,
+        session_token: Option<String>,
+    ) -> NativeSsmClient {
+        NativeSsmClient {
+            http: Client::new(),
+            region: region.into(),
+            access_key_id: access_key_id.into(),
+            secret_access_key: secret_access_key.into(),
+            session_token,
+        }
+    }
+
+    /// Sends a single signed request for `target` (e.g. `GetParameter`) and
+    /// decodes the response, or `other_error` if the request reached SSM but
+    /// was rejected for a reason other than bad credentials.
+    async fn call<Req, Res>(
+        &self,
+        target: &str,
+        request: &Req,
+        other_error: impl FnOnce(String) -> ParameterError,
+    ) -> Result<Res, ParameterError>
+    where
+        Req: Serialize,
+        Res: DeserializeOwned,
+    {
+        let body = serde_json::to_string(request).map_err(|error| io_error(error.to_string()))?;
+
+        let host = format!("ssm.{}.amazonaws.com", self.region);
+        let amz_target = format!("AmazonSSM.{}", target);
+        let amz_date = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+
+        let signed = sign_request(
+            &self.access_key_id,
+            &self.secret_access_key,
+            self.session_token.as_deref(),
+            &self.region,
+            &host,
+            &amz_target,
+            &body,
+            &amz_date,
+        );
+
+        let mut request_builder = self
+            .http
+            .post(format!("https://{}/", host))
+            .header("Content-Type", "application/x-amz-json-1.1")
+            .header("X-Amz-Target", &amz_target)
+            .header("X-Amz-Date", &amz_date)
+            .header("Authorization", &signed.authorization)
+            .body(body);
+
+        if let Some(token) = &self.session_token {
+            request_builder = request_builder.header("X-Amz-Security-Token", token);
+        }
+
+        let response = request_builder
+            .send()
+            .await
+            .map_err(|error| io_error(error.to_string()))?;
+
+        let status = response.status();
+        let text = response.text().await.map_err(|error| io_error(error.to_string()))?;
+
+        if status.is_success() {
+            return serde_json::from_str(&text).map_err(|error| io_error(error.to_string()));
+        }
+
+        let error_body: AwsErrorBody = serde_json::from_str(&text).unwrap_or_default();
+
+        if CREDENTIALS_ERROR_TYPES
+            .iter()
+            .any(|known_type| error_body.error_type.contains(known_type))
+        {
+            Err(ParameterError::Credentials {
+                message: if error_body.message.is_empty() {
+                    text
+                } else {
+                    error_body.message
+                },
+            })
+        } else {
+            Err(other_error(if error_body.message.is_empty() {
+                text
+            } else {
+                error_body.message
+            }))
+        }
+    }
+
+    /// Reads a single parameter.
+    pub async fn get_parameter(
+        &self,
+        request: GetParameterRequest,
+    ) -> Result<GetParameterResult, ParameterError> {
+        let name = request.name.clone();
+        self.call("GetParameter", &request, |_message| ParameterError::NotFound { name })
+            .await
+    }
+
+    /// Writes a single parameter.
+    pub async fn put_parameter(
+        &self,
+        request: PutParameterRequest,
+    ) -> Result<PutParameterResult, ParameterError> {
+        let name = request.name.clone();
+        self.call("PutParameter", &request, |message| ParameterError::PutFailed {
+            name,
+            message,
+        })
+        .await
+    }
+
+    /// Deletes a single parameter.
+    pub async fn delete_parameter(
+        &self,
+        request: DeleteParameterRequest,
+    ) -> Result<DeleteParameterResult, ParameterError> {
+        let name = request.name.clone();
+        self.call("DeleteParameter", &request, |_message| ParameterError::NotFound { name })
+            .await
+    }
+
+    /// Lists parameters under a path.
+    pub async fn get_parameters_by_path(
+        &self,
+        request: GetParametersByPathRequest,
+    ) -> Result<GetParametersByPathResult, ParameterError> {
+        let path = request.path.clone();
+        self.call("GetParametersByPath", &request, |_message| ParameterError::NotFound {
+            name: path,
+        })
+        .await
+    }
+}
+
+fn io_error(message: String) -> ParameterError {
+    ParameterError::IoError(std::io::Error::new(std::io::ErrorKind::Other, message))
+}
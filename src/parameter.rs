@@ -1,9 +1,48 @@
-use log::{error, info};
-use rusoto_core::RusotoError;
+use crate::ssm_client::SsmClient;
+use crate::ParameterError;
+use log::info;
+use rusoto_ssm::DeleteParameterRequest;
 use rusoto_ssm::GetParameterRequest;
 use rusoto_ssm::PutParameterRequest;
-use rusoto_ssm::{Ssm, SsmClient};
-use std::option::NoneError;
+
+/// Fetches a single parameter's (decrypted) value by name, independent of the
+/// `Parameter` struct, for callers that only want to read a value rather than
+/// reconcile it against one they intend to write.
+pub(crate) async fn fetch_value(client: &SsmClient, name: &str) -> Result<String, ParameterError> {
+    let request = GetParameterRequest {
+        name: name.to_string(),
+        with_decryption: Some(true),
+    };
+
+    client
+        .get_parameter(request)
+        .await?
+        .parameter
+        .and_then(|parameter| parameter.value)
+        .ok_or_else(|| ParameterError::NotFound {
+            name: name.to_string(),
+        })
+}
+
+/// Deletes a single parameter by name.
+pub(crate) async fn delete_value(client: &SsmClient, name: &str) -> Result<(), ParameterError> {
+    let request = DeleteParameterRequest {
+        name: name.to_string(),
+    };
+
+    client.delete_parameter(request).await?;
+
+    Ok(())
+}
+
+/// Result of reconciling one `Parameter` against what's already in SSM.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UpdateOutcome {
+    /// The parameter did not match what's in SSM and was written
+    Updated(String),
+    /// The parameter already matched what's in SSM, so nothing was written
+    Unchanged(String),
+}
 
 /// Parameter struct
 ///
@@ -54,11 +93,10 @@ impl Parameter {
     /// # Example
     ///
     /// ```
-    /// use aws_parameter_update::Parameter;
+    /// use aws_parameter_update::{Parameter, SsmClient};
     /// use rusoto_core::Region;
-    /// use rusoto_ssm::SsmClient;
     ///
-    /// let client = SsmClient::new(Region::UsWest2);
+    /// let client = SsmClient::new(rusoto_ssm::SsmClient::new(Region::UsWest2));
     ///
     /// let parameter = Parameter {
     ///     name: "name".into(),
@@ -68,51 +106,41 @@ impl Parameter {
     /// };
     ///
     /// match tokio_test::block_on(parameter.update(&client)) {
-    ///     Ok(parameter_name) => println!("Parameter {} processed", parameter_name),
+    ///     Ok(outcome) => println!("Parameter processed: {:?}", outcome),
     ///     Err(_error) => println!("Parameter not updated"),
     /// }
     /// ```
-    pub async fn update(&self, client: &SsmClient) -> Result<String, NoneError> {
+    pub async fn update(&self, client: &SsmClient) -> Result<UpdateOutcome, ParameterError> {
         if self.needs_updating(client).await? {
             info!("Parameter {} needs updating", self.name);
 
-            let parameter_request = self.to_put_parameter_request();
+            client.put_parameter(self.to_put_parameter_request()).await?;
+            info!("Parameter {} successfully updated", self.name);
 
-            match client.put_parameter(parameter_request).await {
-                Ok(_parameter_result) => info!("Parameter {} successfully updated", self.name),
-                Err(error) => error!("Parameter {} failed to update: {}", self.name, error),
-            }
+            Ok(UpdateOutcome::Updated(self.name.clone()))
         } else {
             info!("Parameter {} does not need updating", self.name);
-        }
 
-        Ok(self.name.clone())
+            Ok(UpdateOutcome::Unchanged(self.name.clone()))
+        }
     }
 
-    async fn needs_updating(&self, client: &SsmClient) -> Result<bool, NoneError> {
-        match client.get_parameter(self.to_get_parameter_request()).await {
-            Ok(parameter_result) => {
-                let existing_value = parameter_result.parameter?.value?;
-
-                info!(
-                    "Found parameter {} with existing value: {}",
-                    self.name, existing_value
-                );
-
-                Ok(self.value != existing_value)
-            }
-            Err(error) => {
-                match error {
-                    RusotoError::Credentials(error) => error!(
-                        "Could not retreive parameter {}: {:?}",
-                        self.name, error.message
-                    ),
-                    _ => error!("Could not retreive parameter {}: {:?}", self.name, error),
-                };
-
-                Err(std::option::NoneError)
-            }
-        }
+    async fn needs_updating(&self, client: &SsmClient) -> Result<bool, ParameterError> {
+        let existing_value = client
+            .get_parameter(self.to_get_parameter_request())
+            .await?
+            .parameter
+            .and_then(|parameter| parameter.value)
+            .ok_or_else(|| ParameterError::NotFound {
+                name: self.name.clone(),
+            })?;
+
+        info!(
+            "Found parameter {} with existing value: {}",
+            self.name, existing_value
+        );
+
+        Ok(self.value != existing_value)
     }
 
     fn to_get_parameter_request(&self) -> GetParameterRequest {
@@ -0,0 +1,128 @@
+//! The inverse of the update path: instead of writing parameters, render a
+//! template file by substituting `%awsssm:/some/param/name%` placeholders with
+//! the current value of that parameter in Parameter Store.
+
+use crate::{ParameterError, ParameterUpdater};
+use log::info;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+
+const PLACEHOLDER_PREFIX: &str = "%awsssm:";
+
+/// Scans `template_path` for `%awsssm:/name%` placeholders, resolves each
+/// distinct one against SSM (caching repeats so a parameter used many times is
+/// only fetched once), and writes the rendered text to `output_path` or stdout
+/// when `output_path` is `None`.
+///
+/// # Example
+///
+/// ```
+/// use aws_parameter_update::{Credentials, ParameterUpdater};
+/// use rusoto_core::Region;
+///
+/// let updater = ParameterUpdater::new(Region::UsWest2, Credentials::Environment).unwrap();
+///
+/// match tokio_test::block_on(aws_parameter_update::resolve_file(&updater, "missing_template.conf", None)) {
+///     Ok(_) => println!("Template rendered"),
+///     Err(error) => println!("Template resolution failed: {}", error),
+/// };
+/// ```
+pub async fn resolve_file(
+    updater: &ParameterUpdater,
+    template_path: &str,
+    output_path: Option<&str>,
+) -> Result<(), Box<dyn Error>> {
+    let template = fs::read_to_string(template_path)?;
+
+    let mut placeholder_names = find_placeholders(&template);
+    placeholder_names.sort();
+    placeholder_names.dedup();
+
+    let mut cache: HashMap<String, String> = HashMap::new();
+    let mut missing = Vec::new();
+
+    for name in &placeholder_names {
+        match updater.get_parameter(name).await {
+            Ok(value) => {
+                cache.insert(name.clone(), value);
+            }
+            Err(ParameterError::NotFound { .. }) => missing.push(name.clone()),
+            Err(error) => return Err(error.into()),
+        }
+    }
+
+    if !missing.is_empty() {
+        return Err(format!(
+            "Could not resolve parameter(s) referenced in {}: {}",
+            template_path,
+            missing.join(", ")
+        )
+        .into());
+    }
+
+    let mut rendered = template;
+    for (name, value) in &cache {
+        rendered = rendered.replace(&format!("{}{}%", PLACEHOLDER_PREFIX, name), value);
+    }
+
+    match output_path {
+        Some(path) => {
+            fs::write(path, rendered)?;
+            info!("Rendered template {} to {}", template_path, path);
+        }
+        None => print!("{}", rendered),
+    }
+
+    Ok(())
+}
+
+/// Finds every `%awsssm:/name%` placeholder in `template`, in order of
+/// appearance, including duplicates.
+fn find_placeholders(template: &str) -> Vec<String> {
+    let mut placeholders = Vec::new();
+    let mut rest = template;
+
+    while let Some(start) = rest.find(PLACEHOLDER_PREFIX) {
+        let after_prefix = &rest[start + PLACEHOLDER_PREFIX.len()..];
+
+        match after_prefix.find('%') {
+            Some(end) => {
+                placeholders.push(after_prefix[..end].to_string());
+                rest = &after_prefix[end + 1..];
+            }
+            None => break,
+        }
+    }
+
+    placeholders
+}
+
+#[cfg(test)]
+mod tests {
+    use super::find_placeholders;
+
+    #[test]
+    fn finds_single_placeholder() {
+        let template = "host: %awsssm:/app/host%";
+
+        assert_eq!(find_placeholders(template), vec!["/app/host".to_string()]);
+    }
+
+    #[test]
+    fn finds_repeated_placeholder_each_time() {
+        let template = "a: %awsssm:/app/host%\nb: %awsssm:/app/host%";
+
+        assert_eq!(
+            find_placeholders(template),
+            vec!["/app/host".to_string(), "/app/host".to_string()]
+        );
+    }
+
+    #[test]
+    fn ignores_text_with_no_placeholders() {
+        let template = "no placeholders here";
+
+        assert!(find_placeholders(template).is_empty());
+    }
+}
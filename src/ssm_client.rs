@@ -0,0 +1,101 @@
+//! Normalizes the two SSM wire backends (rusoto, and the native SigV4 client
+//! behind the `native-sigv4` feature) behind one set of method signatures, so
+//! `Parameter::update` and friends don't need to know which one is compiled in.
+
+#[cfg(not(feature = "native-sigv4"))]
+pub use rusoto_backend::SsmClient;
+
+#[cfg(feature = "native-sigv4")]
+pub use native_backend::SsmClient;
+
+#[cfg(not(feature = "native-sigv4"))]
+mod rusoto_backend {
+    use crate::ParameterError;
+    use rusoto_ssm::{
+        DeleteParameterRequest, DeleteParameterResult, GetParameterRequest, GetParameterResult,
+        GetParametersByPathRequest, GetParametersByPathResult, PutParameterRequest,
+        PutParameterResult, RusotoError, Ssm,
+    };
+
+    /// SSM client backed by the rusoto stack (the default backend).
+    pub struct SsmClient(pub(crate) rusoto_ssm::SsmClient);
+
+    impl SsmClient {
+        /// Wraps an existing rusoto `SsmClient`.
+        pub fn new(client: rusoto_ssm::SsmClient) -> SsmClient {
+            SsmClient(client)
+        }
+
+        /// Reads a single parameter.
+        pub async fn get_parameter(
+            &self,
+            request: GetParameterRequest,
+        ) -> Result<GetParameterResult, ParameterError> {
+            let name = request.name.clone();
+            self.0
+                .get_parameter(request)
+                .await
+                .map_err(|error| not_found_unless_credentials(error, &name))
+        }
+
+        /// Writes a single parameter.
+        pub async fn put_parameter(
+            &self,
+            request: PutParameterRequest,
+        ) -> Result<PutParameterResult, ParameterError> {
+            let name = request.name.clone();
+            self.0.put_parameter(request).await.map_err(|error| match error {
+                RusotoError::Credentials(error) => ParameterError::Credentials {
+                    message: error.message,
+                },
+                other => ParameterError::PutFailed {
+                    name: name.clone(),
+                    message: other.to_string(),
+                },
+            })
+        }
+
+        /// Deletes a single parameter.
+        pub async fn delete_parameter(
+            &self,
+            request: DeleteParameterRequest,
+        ) -> Result<DeleteParameterResult, ParameterError> {
+            let name = request.name.clone();
+            self.0
+                .delete_parameter(request)
+                .await
+                .map_err(|error| not_found_unless_credentials(error, &name))
+        }
+
+        /// Lists parameters under a path.
+        pub async fn get_parameters_by_path(
+            &self,
+            request: GetParametersByPathRequest,
+        ) -> Result<GetParametersByPathResult, ParameterError> {
+            let path = request.path.clone();
+            self.0
+                .get_parameters_by_path(request)
+                .await
+                .map_err(|error| not_found_unless_credentials(error, &path))
+        }
+    }
+
+    fn not_found_unless_credentials<E: std::error::Error>(
+        error: RusotoError<E>,
+        name: &str,
+    ) -> ParameterError {
+        match error {
+            RusotoError::Credentials(error) => ParameterError::Credentials {
+                message: error.message,
+            },
+            _other => ParameterError::NotFound {
+                name: name.to_string(),
+            },
+        }
+    }
+}
+
+#[cfg(feature = "native-sigv4")]
+mod native_backend {
+    pub use crate::native_ssm::NativeSsmClient as SsmClient;
+}
@@ -0,0 +1,236 @@
+//! `ParameterUpdater` ties an SSM client to a region and credential source, so
+//! library callers can inject a provider instead of being locked to us-west-2
+//! and the default credential chain.
+
+use crate::config::{build_client, AppConfig, Credentials};
+use crate::parameter::{delete_value, fetch_value, UpdateOutcome};
+use crate::ssm_client::SsmClient;
+use crate::{read_parameters_yaml, Parameter};
+use futures::stream::{self, StreamExt};
+use log::{error, info};
+use rusoto_core::Region;
+use rusoto_ssm::GetParametersByPathRequest;
+use std::error::Error;
+use yaml_rust::{Yaml, YamlEmitter};
+
+/// Number of parameter updates allowed in flight at once when a `ParameterUpdater`
+/// isn't given an explicit concurrency via [`ParameterUpdater::with_concurrency`].
+pub const DEFAULT_CONCURRENCY: usize = 8;
+
+/// How a batch of parameter updates went.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct UpdateSummary {
+    /// Number of parameters that did not match SSM and were written
+    pub updated: usize,
+    /// Number of parameters that already matched SSM
+    pub unchanged: usize,
+    /// Number of parameters that failed to update
+    pub failed: usize,
+}
+
+/// Drives parameter updates against a particular region/credentials pair.
+pub struct ParameterUpdater {
+    client: SsmClient,
+    concurrency: usize,
+}
+
+impl ParameterUpdater {
+    /// Builds an updater for the given region using the given credential source.
+    ///
+    /// # Example
+    /// ```
+    /// use aws_parameter_update::{Credentials, ParameterUpdater};
+    /// use rusoto_core::Region;
+    ///
+    /// let updater = ParameterUpdater::new(Region::UsWest2, Credentials::Environment)
+    ///     .expect("failed to build SSM client");
+    /// ```
+    pub fn new(region: Region, credentials: Credentials) -> Result<ParameterUpdater, Box<dyn Error>> {
+        let client = build_client(region, credentials)?;
+        Ok(ParameterUpdater {
+            client,
+            concurrency: DEFAULT_CONCURRENCY,
+        })
+    }
+
+    /// Builds an updater from a loaded `AppConfig`.
+    pub fn from_config(config: &AppConfig) -> Result<ParameterUpdater, Box<dyn Error>> {
+        ParameterUpdater::new(config.region.clone(), config.credentials.clone())
+    }
+
+    /// Overrides how many parameter updates are allowed in flight at once.
+    /// Defaults to [`DEFAULT_CONCURRENCY`].
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency;
+        self
+    }
+
+    /// Updates AWS Parameters from a YAML file.
+    pub async fn update_from_file(&self, filename: &str) -> Result<(), Box<dyn Error>> {
+        let parameters_from_yaml = read_parameters_yaml(&filename)?;
+
+        self.update_parameters(parameters_from_yaml).await?;
+        Ok(())
+    }
+
+    /// Updates a single AWS Parameter from calling function input.
+    pub async fn update_parameter(
+        &self,
+        name: &str,
+        value: &str,
+        description: &str,
+        is_secure: bool,
+    ) -> Result<(), Box<dyn Error>> {
+        self.update_parameters(vec![Parameter::new(name, value, description, is_secure)])
+            .await?;
+        Ok(())
+    }
+
+    /// Reads a single parameter's (decrypted) value straight from SSM.
+    pub async fn get_parameter(&self, name: &str) -> Result<String, crate::ParameterError> {
+        fetch_value(&self.client, name).await
+    }
+
+    /// Updates AWS Parameters from calling function input, with up to
+    /// `self.concurrency` get/put round-trips to SSM in flight at once. One
+    /// parameter failing to update does not abort the rest of the batch.
+    pub async fn update_parameters(
+        &self,
+        parameters: Vec<Parameter>,
+    ) -> Result<UpdateSummary, Box<dyn Error>> {
+        let client = &self.client;
+        let mut summary = UpdateSummary::default();
+
+        let mut results = stream::iter(parameters)
+            .map(|parameter| async move { parameter.update(client).await })
+            .buffer_unordered(self.concurrency);
+
+        while let Some(result) = results.next().await {
+            match result {
+                Ok(UpdateOutcome::Updated(name)) => {
+                    info!("Parameter {} successfully updated", name);
+                    summary.updated += 1;
+                }
+                Ok(UpdateOutcome::Unchanged(name)) => {
+                    info!("Parameter {} does not need updating", name);
+                    summary.unchanged += 1;
+                }
+                Err(error) => {
+                    error!("Parameter not updated: {}", error);
+                    summary.failed += 1;
+                }
+            }
+        }
+
+        info!("Parameter update finished running: {:?}", summary);
+        Ok(summary)
+    }
+
+    /// Deletes a single parameter by name.
+    pub async fn delete_parameter(&self, name: &str) -> Result<(), Box<dyn Error>> {
+        delete_value(&self.client, name).await?;
+
+        info!("Parameter {} deleted", name);
+        Ok(())
+    }
+
+    /// Lists every parameter under `path_prefix`, optionally walking nested paths,
+    /// following `GetParametersByPath`'s `NextToken` cursor until it is exhausted.
+    pub async fn list_parameters(
+        &self,
+        path_prefix: &str,
+        recursive: bool,
+    ) -> Result<Vec<Parameter>, Box<dyn Error>> {
+        let mut all_parameters = Vec::new();
+        let mut next_token = None;
+
+        loop {
+            let (mut page, token) =
+                fetch_parameters_page(&self.client, path_prefix, recursive, next_token).await?;
+            all_parameters.append(&mut page);
+
+            next_token = token;
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        Ok(all_parameters)
+    }
+
+    /// Exports every parameter under `path_prefix` as the YAML schema
+    /// `read_parameters_yaml` understands, so it can be re-imported elsewhere.
+    pub async fn export_parameters_yaml(
+        &self,
+        path_prefix: &str,
+        recursive: bool,
+    ) -> Result<String, Box<dyn Error>> {
+        let parameters = self.list_parameters(path_prefix, recursive).await?;
+
+        let documents: Vec<Yaml> = parameters
+            .into_iter()
+            .map(|parameter| {
+                let mut entries = yaml_rust::yaml::Hash::new();
+                entries.insert(Yaml::from_str("name"), Yaml::String(parameter.name.clone()));
+                entries.insert(Yaml::from_str("value"), Yaml::String(parameter.value.clone()));
+                entries.insert(
+                    Yaml::from_str("description"),
+                    Yaml::String(parameter.description.clone()),
+                );
+                entries.insert(Yaml::from_str("is_secure"), Yaml::Boolean(parameter.is_secure));
+                Yaml::Hash(entries)
+            })
+            .collect();
+
+        let mut rendered = String::new();
+        let mut emitter = YamlEmitter::new(&mut rendered);
+        emitter.dump(&Yaml::Array(documents))?;
+
+        Ok(rendered)
+    }
+}
+
+/// Issues a single `GetParametersByPath` request and returns the page of
+/// parameters alongside the cursor to pass back in for the next page, if any.
+async fn fetch_parameters_page(
+    client: &SsmClient,
+    path_prefix: &str,
+    recursive: bool,
+    next_token: Option<String>,
+) -> Result<(Vec<Parameter>, Option<String>), Box<dyn Error>> {
+    let request = GetParametersByPathRequest {
+        path: path_prefix.to_string(),
+        recursive: Some(recursive),
+        with_decryption: Some(true),
+        next_token,
+        ..Default::default()
+    };
+
+    let page = client.get_parameters_by_path(request).await?;
+
+    let parameters = page
+        .parameters
+        .unwrap_or_default()
+        .into_iter()
+        .map(|parameter| {
+            Parameter::new(
+                parameter.name.unwrap_or_default(),
+                parameter.value.unwrap_or_default(),
+                // GetParametersByPath does not return descriptions
+                "".to_string(),
+                parameter.type_.as_deref() == Some("SecureString"),
+            )
+        })
+        .collect();
+
+    Ok((parameters, page.next_token))
+}
+
+impl Default for ParameterUpdater {
+    /// Builds an updater for us-west-2 using the default credential chain, matching
+    /// this crate's historical behavior before region/credentials were configurable.
+    fn default() -> Self {
+        ParameterUpdater::new(Region::UsWest2, Credentials::default())
+            .expect("failed to build default SSM client")
+    }
+}